@@ -0,0 +1,58 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! The context given to widgets in [`Widget::accessibility`](super::Widget::accessibility).
+
+use accesskit::{Node, NodeId};
+
+use super::{WidgetId, WidgetState};
+
+/// A context provided to [`Widget::accessibility`](super::Widget::accessibility) methods.
+///
+/// This context is a wrapper around [`accesskit`]'s tree-building types. By the
+/// time a widget's `accessibility` method runs, the pass driving this context
+/// (see [`run_update_accessibility_pass`](crate::passes::accessibility::run_update_accessibility_pass))
+/// has already stamped the widget's role and window-relative bounds onto its
+/// `node`, and will automatically recurse into the widget's real children and
+/// wire their ids into `node`'s `children` list once this method returns.
+pub struct AccessCtx<'a> {
+    pub(crate) widget_state: &'a WidgetState,
+    /// Every node built so far this pass, across the whole tree, in the form
+    /// the final [`accesskit::TreeUpdate`] needs.
+    pub(crate) nodes: &'a mut Vec<(NodeId, Node)>,
+    /// The ids of the nodes that should be direct `accesskit` children of the
+    /// widget currently being visited, besides its real child widgets (which
+    /// the pass appends automatically after this context is dropped).
+    pub(crate) children: &'a mut Vec<NodeId>,
+    /// Whether a screen reader (or other assistive technology) is currently
+    /// attached. Widgets can use this to skip expensive accessibility work
+    /// (e.g. building large text transcripts) when nothing is listening.
+    pub(crate) is_active: bool,
+}
+
+impl AccessCtx<'_> {
+    /// The [`WidgetId`] of the widget currently being visited.
+    pub fn widget_id(&self) -> WidgetId {
+        self.widget_state.id
+    }
+
+    /// Returns `true` if an assistive technology is attached and accessibility
+    /// info is actually being consumed this pass.
+    pub fn is_active(&self) -> bool {
+        self.is_active
+    }
+
+    /// Add an extra `accesskit` node as a child of the widget currently being
+    /// visited, for content that doesn't correspond to one of its actual child
+    /// widgets (for instance, a virtualized list exposing rows that aren't
+    /// currently mounted).
+    ///
+    /// Widgets don't need (and shouldn't need) to call this for their real
+    /// children: the pass recurses into those on its own and appends them to
+    /// `node`'s `children` list automatically after
+    /// [`Widget::accessibility`](super::Widget::accessibility) returns.
+    pub fn push_node(&mut self, id: NodeId, node: Node) {
+        self.children.push(id);
+        self.nodes.push((id, node));
+    }
+}
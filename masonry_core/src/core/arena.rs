@@ -0,0 +1,97 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! The arena that owns every widget and its associated [`WidgetState`].
+
+use std::collections::HashMap;
+
+use super::{Widget, WidgetId, WidgetState};
+
+/// Owns every widget currently mounted in the tree, plus its [`WidgetState`].
+///
+/// Passes walk the tree by starting from the root id and following
+/// [`Widget::children_ids`] through this arena, rather than holding direct
+/// references between widgets.
+pub struct WidgetArena {
+    pub(crate) widgets: HashMap<WidgetId, Box<dyn Widget>>,
+    pub(crate) states: HashMap<WidgetId, WidgetState>,
+    /// Parent of every widget that's been walked at least once, discovered as
+    /// a side effect of [`for_each_child`](WidgetArena::for_each_child).
+    /// Used to bubble a dirty flag up from a widget to the root as soon as
+    /// it's set, so a later pass can tell from the root alone whether a given
+    /// subtree needs revisiting, without having to walk down into it first.
+    pub(crate) parents: HashMap<WidgetId, WidgetId>,
+}
+
+impl WidgetArena {
+    #[allow(dead_code, reason = "only constructed by this crate's tests")]
+    pub(crate) fn new() -> Self {
+        WidgetArena {
+            widgets: HashMap::new(),
+            states: HashMap::new(),
+            parents: HashMap::new(),
+        }
+    }
+
+    /// Returns `true` if `id` refers to a widget that's still mounted.
+    pub fn has(&self, id: WidgetId) -> bool {
+        self.widgets.contains_key(&id)
+    }
+
+    pub(crate) fn widget_mut(&mut self, id: WidgetId) -> &mut dyn Widget {
+        self.widgets.get_mut(&id).expect("widget not in arena").as_mut()
+    }
+
+    pub(crate) fn state_mut(&mut self, id: WidgetId) -> &mut WidgetState {
+        self.states.get_mut(&id).expect("widget state not in arena")
+    }
+
+    /// Returns the widget and its state together, borrowed from the arena's
+    /// two disjoint maps so callers can use both at once without re-borrowing
+    /// the arena itself in between (which [`widget_mut`](WidgetArena::widget_mut)
+    /// and [`state_mut`](WidgetArena::state_mut) can't do, since each takes
+    /// `&mut self` on its own).
+    pub(crate) fn widget_and_state_mut(&mut self, id: WidgetId) -> (&mut dyn Widget, &WidgetState) {
+        let widget = self.widgets.get_mut(&id).expect("widget not in arena").as_mut();
+        let state = self.states.get(&id).expect("widget state not in arena");
+        (widget, state)
+    }
+
+    /// Runs `f` once per child of `id`, passing this same arena back in so `f`
+    /// can recurse further down, and records each child's parent so that a
+    /// later [`request_accessibility_update`](WidgetArena::request_accessibility_update)
+    /// on it (or on one of its own descendants) can bubble back up here.
+    pub(crate) fn for_each_child(
+        &mut self,
+        id: WidgetId,
+        mut f: impl FnMut(&mut WidgetArena, WidgetId),
+    ) {
+        let children = self.widget_mut(id).children_ids();
+        for child_id in children {
+            self.parents.insert(child_id, id);
+            f(self, child_id);
+        }
+    }
+
+    /// Mark `id`'s accessibility node as needing to be rebuilt, and bubble
+    /// that up through every known ancestor, so the accessibility pass can
+    /// skip straight past any subtree it doesn't touch.
+    ///
+    /// Stops as soon as it reaches an ancestor that's already marked dirty,
+    /// since everything above it must already be marked too.
+    pub(crate) fn request_accessibility_update(&mut self, id: WidgetId) {
+        let mut current = id;
+        loop {
+            let state = self.state_mut(current);
+            if state.needs_accessibility {
+                return;
+            }
+            state.needs_accessibility = true;
+
+            match self.parents.get(&current) {
+                Some(&parent) => current = parent,
+                None => return,
+            }
+        }
+    }
+}
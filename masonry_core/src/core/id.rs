@@ -0,0 +1,40 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! The [`WidgetId`] type, used to identify widgets across passes.
+
+use std::num::NonZeroU64;
+
+/// A unique identifier for a widget, stable for the lifetime of that widget.
+///
+/// Widget ids are also used as `accesskit` node ids, so that a node produced by
+/// the accessibility pass (see [`crate::passes::accessibility`]) can always be
+/// traced back to the widget that produced it, and an incoming
+/// [`accesskit::ActionRequest`] can always be routed back to its target.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
+pub struct WidgetId(pub(crate) NonZeroU64);
+
+impl WidgetId {
+    /// Returns a value suitable for use in a [`tracing::Span`].
+    pub fn trace(self) -> u64 {
+        self.0.get()
+    }
+}
+
+impl From<WidgetId> for accesskit::NodeId {
+    fn from(id: WidgetId) -> accesskit::NodeId {
+        accesskit::NodeId(id.0.get())
+    }
+}
+
+impl WidgetId {
+    /// Converts an `accesskit` node id coming from a platform adapter back into
+    /// a [`WidgetId`], if it refers to a widget that could plausibly exist.
+    ///
+    /// This doesn't check that the widget is actually still present in the
+    /// tree; callers should check that separately (see
+    /// [`WidgetArena::has`](crate::core::WidgetArena::has)).
+    pub fn try_from_accesskit(id: accesskit::NodeId) -> Option<WidgetId> {
+        NonZeroU64::new(id.0).map(WidgetId)
+    }
+}
@@ -0,0 +1,17 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! The core types every widget and pass is built on: ids, state, the arena that
+//! owns both, and the [`Widget`] trait itself.
+
+mod access_ctx;
+mod arena;
+mod id;
+mod widget;
+mod widget_state;
+
+pub use access_ctx::AccessCtx;
+pub use arena::WidgetArena;
+pub use id::WidgetId;
+pub use widget::Widget;
+pub use widget_state::WidgetState;
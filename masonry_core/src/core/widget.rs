@@ -0,0 +1,64 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! The [`Widget`] trait, the core abstraction every element of a Masonry tree
+//! implements.
+
+use accesskit::{Node, Role};
+
+use super::{AccessCtx, WidgetId};
+
+/// The trait implemented by every widget in a Masonry tree.
+///
+/// Only the accessibility-related parts of the trait are shown here; see the
+/// [pass system doc](crate::doc::pass_system) for how this hook fits in with
+/// the other passes a widget participates in.
+pub trait Widget {
+    /// The `accesskit` role this widget should be reported as, used to build
+    /// its node before [`accessibility`](Widget::accessibility) is called.
+    ///
+    /// Defaults to [`Role::Unknown`], which is appropriate for widgets that are
+    /// purely layout (e.g. a `Flex` container) and don't need their own node
+    /// beyond grouping their children.
+    fn accessibility_role(&self) -> Role {
+        Role::Unknown
+    }
+
+    /// Populate this widget's `accesskit` node.
+    ///
+    /// This is called once per widget by
+    /// [`run_update_accessibility_pass`](crate::passes::accessibility::run_update_accessibility_pass),
+    /// skipping widgets whose subtree doesn't need an update. By the time this
+    /// is called, `node` already has the widget's role and window-relative
+    /// bounds filled in by the pass; implementations should set whatever else
+    /// applies to them (label, value, default action, etc.) via `node`'s
+    /// setters.
+    ///
+    /// Widgets don't need to recurse into their own children here: the pass
+    /// does that automatically right after this call returns, and stitches the
+    /// resulting child ids onto `node` itself. Use
+    /// [`AccessCtx::push_node`] only for extra `accesskit` nodes that don't
+    /// correspond to one of this widget's actual children (for instance, a
+    /// virtualized list exposing rows that aren't currently mounted as real
+    /// child widgets).
+    #[allow(unused_variables)]
+    fn accessibility(&mut self, ctx: &mut AccessCtx<'_>, node: &mut Node) {}
+
+    /// The ids of this widget's children, in paint order.
+    ///
+    /// Used by every pass that needs to recurse through the tree, including
+    /// the accessibility pass.
+    fn children_ids(&self) -> Vec<WidgetId>;
+
+    /// Handle an `accesskit` action routed to this widget by
+    /// [`run_access_action_pass`](crate::passes::accessibility::run_access_action_pass),
+    /// for example because the user invoked this widget through a screen
+    /// reader.
+    ///
+    /// The caller already re-marks this widget's own accessibility node dirty
+    /// after this returns, so implementations only need to request whatever
+    /// *other* invalidation applies to them (e.g. requesting layout, if the
+    /// action changed something that affects it).
+    #[allow(unused_variables)]
+    fn on_access_event(&mut self, action: accesskit::Action, data: Option<accesskit::ActionData>) {}
+}
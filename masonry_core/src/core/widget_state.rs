@@ -0,0 +1,56 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Per-widget state tracked by the arena, independently of the widget's own data.
+
+use kurbo::{Rect, Size};
+
+use super::WidgetId;
+
+/// State Masonry keeps for every widget, alongside the widget itself.
+///
+/// This holds the dirty flags that drive each pass: a pass only visits a
+/// widget (and its subtree) when the flag it cares about is set, and clears
+/// the flag once it has brought that widget up to date.
+pub struct WidgetState {
+    pub(crate) id: WidgetId,
+
+    /// The widget's bounds, in window coordinates, as of the last layout pass.
+    /// This is what [`build_accessibility_tree`](crate::passes::accessibility)
+    /// stamps onto each node's bounds before handing off to
+    /// [`Widget::accessibility`](super::Widget::accessibility).
+    pub(crate) window_layout_rect: Rect,
+
+    /// Set whenever this widget's accessibility node may be stale: on
+    /// creation, and whenever
+    /// [`WidgetArena::request_accessibility_update`](super::WidgetArena::request_accessibility_update)
+    /// is called for this widget or one of its descendants. A change to
+    /// `window_layout_rect` doesn't set it on its own. Cleared by
+    /// [`run_update_accessibility_pass`](crate::passes::accessibility::run_update_accessibility_pass)
+    /// once that widget's node has been rebuilt.
+    pub(crate) needs_accessibility: bool,
+}
+
+impl WidgetState {
+    #[allow(dead_code, reason = "only constructed by this crate's tests")]
+    pub(crate) fn new(id: WidgetId, size: Size) -> Self {
+        WidgetState {
+            id,
+            window_layout_rect: Rect::from_origin_size((0.0, 0.0), size),
+            // Every widget starts out needing an accessibility node.
+            needs_accessibility: true,
+        }
+    }
+
+    /// This widget's bounds, in window coordinates, as an `accesskit` rect.
+    ///
+    /// Stamped onto every node by
+    /// [`run_update_accessibility_pass`](crate::passes::accessibility::run_update_accessibility_pass)
+    /// before the widget's own [`Widget::accessibility`](super::Widget::accessibility)
+    /// runs, since the bounds are common to every node and widgets have no
+    /// other way to learn their own window-relative position.
+    pub(crate) fn accessibility_bounds(&self) -> accesskit::Rect {
+        let rect = self.window_layout_rect;
+        accesskit::Rect::new(rect.x0, rect.y0, rect.x1, rect.y1)
+    }
+}
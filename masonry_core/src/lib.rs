@@ -0,0 +1,10 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! The platform-agnostic core of Masonry: widgets, passes, and the render root
+//! that drives them.
+
+pub mod core;
+pub mod doc;
+pub mod passes;
+pub mod render_root;
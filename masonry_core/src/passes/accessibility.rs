@@ -0,0 +1,334 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! The accessibility pass, which builds an [`accesskit::TreeUpdate`] describing
+//! the current widget tree, and routes incoming [`accesskit::ActionRequest`]s
+//! back to their target widget.
+//!
+//! See the [pass system doc](crate::doc::pass_system) for an overview of how this
+//! pass fits in with the others.
+
+use accesskit::{ActionRequest, Node, NodeId, Role, Tree, TreeUpdate};
+use tracing::{info_span, trace};
+
+use crate::core::{AccessCtx, WidgetArena, WidgetId};
+use crate::render_root::RenderRoot;
+
+// --- MARK: UPDATE ACCESSIBILITY ---
+
+/// Run the accessibility pass, which populates an [`accesskit::TreeUpdate`] for
+/// the whole widget tree.
+///
+/// This pass respects the same dirty-flag propagation as the other update
+/// passes: a widget whose `needs_accessibility` flag isn't set is skipped
+/// along with rebuilding its node, and the node `accesskit` already has for it
+/// is left untouched; only its id is reused so its parent can still list it as
+/// a child.
+pub(crate) fn run_update_accessibility_pass(root: &mut RenderRoot, is_active: bool) -> TreeUpdate {
+    let mut nodes = Vec::new();
+    let root_id = root.root_id;
+
+    build_accessibility_tree(&mut root.widget_arena, &mut nodes, root_id, is_active);
+
+    TreeUpdate {
+        nodes,
+        // The adapter needs to be told the tree's root on every update, not
+        // just the first one, since it has no other way to know whether a
+        // connection was just (re-)established.
+        tree: Some(Tree::new(root_id.into())),
+        focus: root.focused_widget().into(),
+    }
+}
+
+/// Build (or reuse) the `accesskit` node for `widget_id` and its subtree,
+/// returning its node id either way so the caller can add it to its own
+/// parent's `children` list.
+fn build_accessibility_tree(
+    arena: &mut WidgetArena,
+    nodes: &mut Vec<(NodeId, Node)>,
+    widget_id: WidgetId,
+    is_active: bool,
+) -> NodeId {
+    let node_id = widget_id.into();
+
+    if !arena.state_mut(widget_id).needs_accessibility {
+        return node_id;
+    }
+
+    let mut children = Vec::new();
+
+    let role = arena.widget_mut(widget_id).accessibility_role();
+    // Kept open across the recursion into children below, so nested widgets'
+    // own spans (and anything they log) nest under their ancestors', matching
+    // the shape of the widget tree itself.
+    let _span =
+        (role != Role::Unknown).then(|| info_span!("accessibility", id = widget_id.trace()).entered());
+
+    // Fetched together (not via two separate `arena.widget_mut`/`arena.state_mut`
+    // calls) since both borrows need to be alive at once while building `node`,
+    // and the arena itself needs to be free again right after for the
+    // recursion into children below.
+    let mut node = {
+        let (widget, state) = arena.widget_and_state_mut(widget_id);
+        let mut node = Node::new(role);
+        node.set_bounds(state.accessibility_bounds());
+
+        let mut ctx = AccessCtx {
+            widget_state: state,
+            nodes,
+            children: &mut children,
+            is_active,
+        };
+        widget.accessibility(&mut ctx, &mut node);
+        node
+    };
+
+    arena.for_each_child(widget_id, |arena, child_id| {
+        let child_node_id = build_accessibility_tree(arena, nodes, child_id, is_active);
+        children.push(child_node_id);
+    });
+
+    node.set_children(children);
+    nodes.push((node_id, node));
+    arena.state_mut(widget_id).needs_accessibility = false;
+    trace!("Built accessibility node for widget {widget_id:?}");
+
+    node_id
+}
+
+// --- MARK: ACTION ROUTING ---
+
+/// Route an [`accesskit::ActionRequest`] coming from the platform adapter back
+/// to the widget it targets, via [`Widget::on_access_event`](crate::core::Widget::on_access_event).
+///
+/// Unlike the other passes, this one doesn't walk the whole tree: it goes
+/// straight to the target widget's id, which the platform reported back to us
+/// as the `accesskit` node id of a node we built in
+/// [`run_update_accessibility_pass`].
+pub(crate) fn run_access_action_pass(root: &mut RenderRoot, request: ActionRequest) {
+    let ActionRequest {
+        action,
+        target,
+        data,
+    } = request;
+    let Some(widget_id) = WidgetId::try_from_accesskit(target) else {
+        trace!("Got accessibility action for an id that isn't a widget, ignoring");
+        return;
+    };
+
+    if !root.widget_arena.has(widget_id) {
+        trace!("Got accessibility action for non-existent widget {widget_id:?}, ignoring");
+        return;
+    }
+
+    root.send_access_event(widget_id, action, data);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::collections::HashMap;
+    use std::rc::Rc;
+
+    use accesskit::Action;
+
+    use crate::core::{WidgetArena, WidgetId};
+    use crate::render_root::{RenderRoot, RenderRootState};
+
+    use super::*;
+
+    /// A widget that just records which `accesskit` actions it was sent, so
+    /// tests can check routing without needing to downcast `dyn Widget`.
+    struct Recorder {
+        children: Vec<WidgetId>,
+        events_received: Rc<RefCell<Vec<Action>>>,
+    }
+
+    impl crate::core::Widget for Recorder {
+        fn children_ids(&self) -> Vec<WidgetId> {
+            self.children.clone()
+        }
+
+        fn on_access_event(&mut self, action: Action, _data: Option<accesskit::ActionData>) {
+            self.events_received.borrow_mut().push(action);
+        }
+    }
+
+    fn widget_id(raw: u64) -> WidgetId {
+        WidgetId::try_from_accesskit(NodeId(raw)).unwrap()
+    }
+
+    /// Handle recording the `accesskit` actions a single test widget was
+    /// sent, so tests can check routing without needing to downcast
+    /// `dyn Widget`.
+    type EventLog = Rc<RefCell<Vec<Action>>>;
+
+    /// A [`test_root`] tree, along with its widget ids and per-widget event
+    /// logs.
+    struct TestTree {
+        root: RenderRoot,
+        ids: [WidgetId; 4],
+        events: HashMap<WidgetId, EventLog>,
+    }
+
+    /// Builds `root -> [child_a -> [grandchild], child_b]`.
+    fn test_root() -> TestTree {
+        let root_id = widget_id(1);
+        let child_a_id = widget_id(2);
+        let child_b_id = widget_id(3);
+        let grandchild_id = widget_id(4);
+        let ids = [root_id, child_a_id, child_b_id, grandchild_id];
+
+        let events: HashMap<_, _> = ids
+            .iter()
+            .map(|&id| (id, Rc::new(RefCell::new(Vec::new()))))
+            .collect();
+
+        let mut arena = WidgetArena::new();
+        arena.widgets.insert(
+            root_id,
+            Box::new(Recorder {
+                children: vec![child_a_id, child_b_id],
+                events_received: events[&root_id].clone(),
+            }),
+        );
+        arena.widgets.insert(
+            child_a_id,
+            Box::new(Recorder {
+                children: vec![grandchild_id],
+                events_received: events[&child_a_id].clone(),
+            }),
+        );
+        arena.widgets.insert(
+            child_b_id,
+            Box::new(Recorder {
+                children: vec![],
+                events_received: events[&child_b_id].clone(),
+            }),
+        );
+        arena.widgets.insert(
+            grandchild_id,
+            Box::new(Recorder {
+                children: vec![],
+                events_received: events[&grandchild_id].clone(),
+            }),
+        );
+
+        for id in ids {
+            arena
+                .states
+                .insert(id, crate::core::WidgetState::new(id, kurbo::Size::ZERO));
+        }
+
+        let root = RenderRoot {
+            root_id,
+            widget_arena: arena,
+            state: RenderRootState {
+                is_ax_active: true,
+                focused_widget: None,
+            },
+        };
+        TestTree { root, ids, events }
+    }
+
+    #[test]
+    fn clean_subtree_is_skipped_and_dirty_descendant_bubbles_to_root() {
+        let TestTree {
+            mut root,
+            ids: [root_id, child_a_id, child_b_id, grandchild_id],
+            ..
+        } = test_root();
+
+        // First pass builds every node, since everything starts dirty.
+        let update = run_update_accessibility_pass(&mut root, true);
+        assert_eq!(update.nodes.len(), 4);
+
+        // Nothing marked itself dirty in between, so the second pass should
+        // skip rebuilding every node...
+        let update = run_update_accessibility_pass(&mut root, true);
+        assert!(update.nodes.is_empty());
+
+        // Dirtying just the grandchild should bubble the flag up through
+        // child_a and the root...
+        root.widget_arena.request_accessibility_update(grandchild_id);
+        let update = run_update_accessibility_pass(&mut root, true);
+        let rebuilt: std::collections::HashSet<_> =
+            update.nodes.iter().map(|(id, _)| *id).collect();
+        assert_eq!(
+            rebuilt,
+            [root_id, child_a_id, grandchild_id]
+                .map(NodeId::from)
+                .into_iter()
+                .collect()
+        );
+        // ...but child_b's unrelated subtree should have been skipped entirely.
+        assert!(!rebuilt.contains(&child_b_id.into()));
+    }
+
+    #[test]
+    fn access_action_routes_to_known_widget_and_ignores_unknown() {
+        let TestTree {
+            mut root,
+            ids: [_, child_a_id, _, _],
+            events,
+        } = test_root();
+        let child_events = events[&child_a_id].clone();
+
+        // Clear every widget's initial dirty flag first, so the check below
+        // shows the action itself re-dirtied the target.
+        run_update_accessibility_pass(&mut root, true);
+        assert!(!root.widget_arena.state_mut(child_a_id).needs_accessibility);
+
+        run_access_action_pass(
+            &mut root,
+            ActionRequest {
+                action: Action::Click,
+                target: child_a_id.into(),
+                data: None,
+            },
+        );
+        assert_eq!(child_events.borrow().as_slice(), [Action::Click]);
+        assert!(
+            root.widget_arena.state_mut(child_a_id).needs_accessibility,
+            "handling the action should have re-dirtied the target widget"
+        );
+
+        // An id that was never handed out as a widget id should be ignored,
+        // not panic, and not reach any widget's `on_access_event`.
+        run_access_action_pass(
+            &mut root,
+            ActionRequest {
+                action: Action::Focus,
+                target: NodeId(0),
+                data: None,
+            },
+        );
+        assert_eq!(child_events.borrow().as_slice(), [Action::Click]);
+    }
+
+    #[test]
+    fn access_focus_action_updates_reported_focus() {
+        let TestTree {
+            mut root,
+            ids: [root_id, child_a_id, _, _],
+            ..
+        } = test_root();
+
+        // With nothing focused yet, `accesskit` requires the root to be
+        // reported.
+        assert_eq!(root.focused_widget(), root_id);
+
+        run_access_action_pass(
+            &mut root,
+            ActionRequest {
+                action: Action::Focus,
+                target: child_a_id.into(),
+                data: None,
+            },
+        );
+        assert_eq!(root.focused_widget(), child_a_id);
+
+        let update = run_update_accessibility_pass(&mut root, true);
+        assert_eq!(update.focus, child_a_id.into());
+    }
+}
@@ -0,0 +1,9 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! The passes Masonry uses to update its widget tree in reaction to external
+//! events.
+//!
+//! See the [pass system doc](crate::doc::pass_system) for a full overview.
+
+pub(crate) mod accessibility;
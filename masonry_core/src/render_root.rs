@@ -0,0 +1,101 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! [`RenderRoot`], the entry point platform shells drive to keep a Masonry
+//! tree up to date and turn it into pixels (and, now, accesskit nodes).
+
+use accesskit::{Action, ActionData, ActionRequest, TreeUpdate};
+
+use crate::core::{WidgetArena, WidgetId};
+use crate::passes::accessibility::{run_access_action_pass, run_update_accessibility_pass};
+
+/// State shared across passes that isn't tied to any single widget.
+pub struct RenderRootState {
+    /// Whether a screen reader or other assistive technology is currently
+    /// attached. Set by the platform shell whenever it gets
+    /// `accesskit::ActivationHandler` callbacks; read by the accessibility
+    /// pass to skip building a tree update that nothing will consume.
+    pub(crate) is_ax_active: bool,
+
+    /// The widget that currently has keyboard focus, if any, kept in sync
+    /// with whatever focus-handling code owns focus changes (e.g. the focus
+    /// pass reacting to Tab, or a pointer-down on a focusable widget) and
+    /// with incoming `accesskit::Action::Focus` requests. Reported to the
+    /// platform adapter as [`TreeUpdate::focus`] by the accessibility pass;
+    /// `accesskit` requires the root to be reported when nothing else has
+    /// focus, so this is `None` rather than defaulting to the root itself.
+    pub(crate) focused_widget: Option<WidgetId>,
+}
+
+/// The root of a Masonry tree, owning every widget and driving every pass.
+pub struct RenderRoot {
+    pub(crate) root_id: WidgetId,
+    pub(crate) widget_arena: WidgetArena,
+    pub(crate) state: RenderRootState,
+}
+
+impl RenderRoot {
+    /// The id of the tree's root widget.
+    pub fn root_id(&self) -> WidgetId {
+        self.root_id
+    }
+
+    /// Record whether a screen reader (or other assistive technology) is
+    /// currently attached, as reported by the platform shell.
+    pub fn set_ax_active(&mut self, is_active: bool) {
+        self.state.is_ax_active = is_active;
+    }
+
+    /// The widget currently reported as having keyboard focus, falling back
+    /// to the root per `accesskit`'s requirement that focus always point at
+    /// some node in the tree.
+    pub fn focused_widget(&self) -> WidgetId {
+        self.state.focused_widget.unwrap_or(self.root_id)
+    }
+
+    /// Record which widget currently has keyboard focus, for the next
+    /// accessibility update to report via [`TreeUpdate::focus`]. Called by
+    /// whatever owns focus changes (e.g. the focus pass moving focus on Tab),
+    /// and also kept in sync with incoming `accesskit::Action::Focus`
+    /// requests in [`send_access_event`](RenderRoot::send_access_event).
+    pub fn set_focused_widget(&mut self, id: Option<WidgetId>) {
+        if self.state.focused_widget != id {
+            self.state.focused_widget = id;
+        }
+    }
+
+    /// Bring the accessibility tree up to date and return the update to hand
+    /// to the platform's `accesskit` adapter.
+    ///
+    /// This is the last step of a full pass cycle, run after layout (so
+    /// widget bounds are current) and before the next frame is requested.
+    /// Returns `None` if no assistive technology is attached, since building a
+    /// tree update that nothing will consume would be wasted work.
+    pub fn update_accessibility_if_needed(&mut self) -> Option<TreeUpdate> {
+        if !self.state.is_ax_active {
+            return None;
+        }
+        Some(run_update_accessibility_pass(self, self.state.is_ax_active))
+    }
+
+    /// Handle an `accesskit::ActionRequest` delivered by the platform's
+    /// adapter (e.g. the user invoked a control through a screen reader).
+    pub fn handle_access_event(&mut self, request: ActionRequest) {
+        run_access_action_pass(self, request);
+    }
+
+    pub(crate) fn send_access_event(
+        &mut self,
+        widget_id: WidgetId,
+        action: Action,
+        data: Option<ActionData>,
+    ) {
+        if action == Action::Focus {
+            self.set_focused_widget(Some(widget_id));
+        }
+        self.widget_arena
+            .widget_mut(widget_id)
+            .on_access_event(action, data);
+        self.widget_arena.request_accessibility_update(widget_id);
+    }
+}